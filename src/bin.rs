@@ -1,5 +1,31 @@
+use std::str::FromStr;
+
 use clap::Parser;
-use post_office::Expression;
+use post_office::{Accidental, Expression, Notation, Operations, PitchClass, Render, Style};
+
+/// The `--notation` output styles available on the command line.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum NotationArg {
+    /// Duodecimal numerals (`0`..`9`, `↊`, `↋`).
+    Duodecimal,
+    /// Decimal integers, with `t`/`e` standing in for `10`/`11`.
+    Decimal,
+    /// Note names spelled with flats.
+    Flat,
+    /// Note names spelled with sharps.
+    Sharp,
+}
+
+impl From<NotationArg> for Style {
+    fn from(arg: NotationArg) -> Self {
+        match arg {
+            NotationArg::Duodecimal => Style::Duodecimal,
+            NotationArg::Decimal => Style::Decimal,
+            NotationArg::Flat => Style::NoteName(Accidental::Flat),
+            NotationArg::Sharp => Style::NoteName(Accidental::Sharp),
+        }
+    }
+}
 
 #[derive(Debug, Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -12,17 +38,114 @@ struct Args {
     #[clap(short, long, default_value = "C")]
     zero: String,
 
+    /// The style to render the expression in.
+    #[clap(short, long, value_enum, default_value = "duodecimal")]
+    notation: NotationArg,
+
+    /// Transpose the expression by this many semitones (Tn).
+    #[clap(short, long)]
+    transpose: Option<isize>,
+
+    /// Invert the expression about 0, then transpose by this many
+    /// semitones (TnI).
+    #[clap(short, long)]
+    invert: Option<isize>,
+
+    /// Multiply the expression by this factor mod 12 (Mm).
+    #[clap(short, long)]
+    multiply: Option<isize>,
+
+    /// Emit a structured JSON analysis instead of the rendered expression.
+    #[cfg(feature = "serde")]
+    #[clap(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+
     #[clap(flatten)]
     verbose: clap_verbosity_flag::Verbosity,
 }
 
+/// The `--format` options available on the command line.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// The rendered expression, per `--notation`.
+    Text,
+    /// A structured analysis of the expression, as JSON.
+    Json,
+}
+
+/// A JSON-serializable analysis of an expression: its pitch classes as
+/// integers, plus set-class analysis when it is a collection.
+#[cfg(feature = "serde")]
+#[derive(Debug, serde::Serialize)]
+struct Analysis {
+    pitch_classes: Vec<u8>,
+    normal_form: Option<Vec<u8>>,
+    prime_form: Option<String>,
+    interval_vector: Option<[u8; 6]>,
+    forte_number: Option<&'static str>,
+}
+
+#[cfg(feature = "serde")]
+impl From<&Expression> for Analysis {
+    fn from(expression: &Expression) -> Self {
+        match expression {
+            Expression::PitchClass(pc) => Analysis {
+                pitch_classes: vec![*pc as u8],
+                normal_form: None,
+                prime_form: None,
+                interval_vector: None,
+                forte_number: None,
+            },
+            Expression::Pitch(p) => Analysis {
+                pitch_classes: vec![p.class() as u8],
+                normal_form: None,
+                prime_form: None,
+                interval_vector: None,
+                forte_number: None,
+            },
+            Expression::Collection(c) => Analysis {
+                pitch_classes: c.pitch_classes().iter().map(|pc| *pc as u8).collect(),
+                normal_form: Some(c.normal_form().iter().map(|pc| *pc as u8).collect()),
+                prime_form: Some(c.prime_form().to_string()),
+                interval_vector: Some(c.interval_vector()),
+                forte_number: c.forte_number(),
+            },
+        }
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     env_logger::Builder::new()
         .filter_level(args.verbose.log_level_filter())
         .init();
 
-    println!("{}", Expression::from_str(&args.expression)?);
+    let notation = Notation {
+        zero: PitchClass::from_str(&args.zero)?,
+        style: args.notation.into(),
+    };
+
+    let mut expression = Expression::from_str_with_notation(&args.expression, &notation)?;
+
+    if let Some(n) = args.transpose {
+        expression = expression.transpose(n);
+    }
+    if let Some(n) = args.invert {
+        expression = expression.invert(n);
+    }
+    if let Some(m) = args.multiply {
+        expression = expression.multiply(m);
+    }
+
+    #[cfg(feature = "serde")]
+    if args.format == OutputFormat::Json {
+        let analysis = Analysis::from(&expression);
+        println!("{}", serde_json::to_string(&analysis)?);
+        return Ok(());
+    }
+
+    println!("{}", expression.render(&notation));
 
     Ok(())
 }
@@ -0,0 +1,327 @@
+use std::{cmp::Ordering, fmt};
+
+use phf::phf_map;
+
+use crate::{notation::Render, operations::Operations, Notation, PitchClass};
+
+/// A collection of pitch classes, either ordered (a pitch sequence, `[]`) or
+/// unordered (a pitch-class set, `{}`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Collection {
+    /// An ordered collection, e.g. `[0, 4, 7]`.
+    Ordered(Vec<PitchClass>),
+    /// An unordered collection, e.g. `{0, 4, 7}`.
+    Unordered(Vec<PitchClass>),
+}
+
+/// The prime form of a set class, e.g. `(037)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimeForm(Vec<PitchClass>);
+
+impl PrimeForm {
+    /// The pitch classes making up this prime form.
+    pub fn pitch_classes(&self) -> &[PitchClass] {
+        &self.0
+    }
+}
+
+impl fmt::Display for PrimeForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for pc in &self.0 {
+            write!(f, "{}", pc)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// Mapping from prime forms to their Forte numbers. Only trichords (3-*) and
+// tetrachords (4-*) are tabulated so far; see `Collection::forte_number`.
+static FORTE_NUMBERS: phf::Map<&'static str, &'static str> = phf_map! {
+    "(012)" => "3-1",
+    "(013)" => "3-2",
+    "(014)" => "3-3",
+    "(015)" => "3-4",
+    "(016)" => "3-5",
+    "(024)" => "3-6",
+    "(025)" => "3-7",
+    "(026)" => "3-8",
+    "(027)" => "3-9",
+    "(036)" => "3-10",
+    "(037)" => "3-11",
+    "(048)" => "3-12",
+    "(0123)" => "4-1",
+    "(0124)" => "4-2",
+    "(0134)" => "4-3",
+    "(0125)" => "4-4",
+    "(0126)" => "4-5",
+    "(0127)" => "4-6",
+    "(0145)" => "4-7",
+    "(0156)" => "4-8",
+    "(0167)" => "4-9",
+    "(0235)" => "4-10",
+    "(0135)" => "4-11",
+    "(0236)" => "4-12",
+    "(0136)" => "4-13",
+    "(0237)" => "4-14",
+    "(0146)" => "4-Z15",
+    "(0157)" => "4-16",
+    "(0347)" => "4-17",
+    "(0147)" => "4-18",
+    "(0148)" => "4-19",
+    "(0158)" => "4-20",
+    "(0246)" => "4-21",
+    "(0247)" => "4-22",
+    "(0257)" => "4-23",
+    "(0248)" => "4-24",
+    "(0268)" => "4-25",
+    "(0358)" => "4-26",
+    "(0258)" => "4-27",
+    "(0369)" => "4-28",
+    "(0137)" => "4-Z29",
+};
+
+impl Collection {
+    /// The pitch classes in this collection, in the order given.
+    pub fn pitch_classes(&self) -> &[PitchClass] {
+        match self {
+            Self::Ordered(pcs) | Self::Unordered(pcs) => pcs,
+        }
+    }
+
+    /// Rebuild this collection with a new list of pitch classes, preserving
+    /// whether it is ordered or unordered.
+    fn with_pitch_classes(&self, pcs: Vec<PitchClass>) -> Self {
+        match self {
+            Self::Ordered(_) => Self::Ordered(pcs),
+            Self::Unordered(_) => Self::Unordered(pcs),
+        }
+    }
+
+    /// The distinct pitch classes in this collection, sorted ascending by
+    /// integer notation.
+    fn distinct_sorted(&self) -> Vec<isize> {
+        let mut pcs: Vec<isize> = self
+            .pitch_classes()
+            .iter()
+            .map(|pc| *pc as isize)
+            .collect();
+        pcs.sort_unstable();
+        pcs.dedup();
+        pcs
+    }
+
+    /// The ascending span from `from` to `to` within `rotation`, mod 12.
+    fn span(rotation: &[isize], from: usize, to: usize) -> isize {
+        (rotation[to] - rotation[from]).rem_euclid(12)
+    }
+
+    /// The normal form of this collection: the 12-cyclic rotation of its
+    /// distinct pitch classes that is most "left-packed", i.e. minimizes the
+    /// span from the first to the last element, breaking ties by minimizing
+    /// the span from the first to each preceding element in turn, and
+    /// finally by the smallest starting integer.
+    pub fn normal_form(&self) -> Vec<PitchClass> {
+        let pcs = self.distinct_sorted();
+        if pcs.is_empty() {
+            return Vec::new();
+        }
+
+        let n = pcs.len();
+        let rotations: Vec<Vec<isize>> = (0..n)
+            .map(|start| (0..n).map(|i| pcs[(start + i) % n]).collect())
+            .collect();
+
+        let best = rotations
+            .iter()
+            .min_by(|a, b| {
+                for to in (1..n).rev() {
+                    match Self::span(a, 0, to).cmp(&Self::span(b, 0, to)) {
+                        Ordering::Equal => continue,
+                        ord => return ord,
+                    }
+                }
+                a[0].cmp(&b[0])
+            })
+            .unwrap();
+
+        best.iter().copied().map(PitchClass::from_int).collect()
+    }
+
+    /// Transpose a set of pitch classes so that its first element is 0.
+    fn transpose_to_zero(pcs: &[PitchClass]) -> Vec<isize> {
+        let first = pcs[0] as isize;
+        pcs.iter()
+            .map(|pc| (*pc as isize - first).rem_euclid(12))
+            .collect()
+    }
+
+    /// The prime form of this collection: the more left-packed of the
+    /// zero-transposed normal form and the zero-transposed normal form of
+    /// its inversion.
+    pub fn prime_form(&self) -> PrimeForm {
+        let normal = self.normal_form();
+        if normal.is_empty() {
+            return PrimeForm(Vec::new());
+        }
+        let prime = Self::transpose_to_zero(&normal);
+
+        let inverted: Vec<PitchClass> = self
+            .pitch_classes()
+            .iter()
+            .map(|pc| PitchClass::from_int((12 - *pc as isize).rem_euclid(12)))
+            .collect();
+        let inverted_normal = self.with_pitch_classes(inverted).normal_form();
+        let inverted_prime = Self::transpose_to_zero(&inverted_normal);
+
+        let most_packed = if inverted_prime < prime {
+            inverted_prime
+        } else {
+            prime
+        };
+
+        PrimeForm(most_packed.into_iter().map(PitchClass::from_int).collect())
+    }
+
+    /// The interval vector of this collection: for each interval class
+    /// `1..=6`, the number of unordered pairs of distinct pitch classes
+    /// separated by that interval class.
+    pub fn interval_vector(&self) -> [u8; 6] {
+        let pcs = self.distinct_sorted();
+        let mut vector = [0u8; 6];
+        for i in 0..pcs.len() {
+            for j in (i + 1)..pcs.len() {
+                let d = (pcs[j] - pcs[i]).rem_euclid(12) as u8;
+                let ic = d.min(12 - d);
+                vector[ic as usize - 1] += 1;
+            }
+        }
+        vector
+    }
+
+    /// This collection's Forte number, e.g. `3-11`.
+    ///
+    /// Only trichords and tetrachords are tabulated; `None` is returned for
+    /// any other cardinality (dyads, pentachords, hexachords, etc.), not
+    /// because the set class lacks a Forte number, but because this crate
+    /// doesn't yet carry that table. Widening `FORTE_NUMBERS` to the rest of
+    /// the cardinalities is future work, not a case this method can detect
+    /// or distinguish from "not tabulated".
+    pub fn forte_number(&self) -> Option<&'static str> {
+        FORTE_NUMBERS
+            .get(self.prime_form().to_string().as_str())
+            .copied()
+    }
+}
+
+impl Operations for Collection {
+    fn transpose(self, n: isize) -> Self {
+        self.map_pitch_classes(|pc| pc.transpose(n))
+    }
+
+    fn invert_about(self, axis: isize) -> Self {
+        self.map_pitch_classes(|pc| pc.invert_about(axis))
+    }
+
+    fn multiply(self, m: isize) -> Self {
+        self.map_pitch_classes(|pc| pc.multiply(m))
+    }
+}
+
+impl Collection {
+    /// Map `f` over every pitch class in this collection, preserving
+    /// ordered/unordered semantics.
+    pub fn map_pitch_classes<F: FnMut(PitchClass) -> PitchClass>(self, f: F) -> Self {
+        match self {
+            Self::Ordered(pcs) => Self::Ordered(pcs.into_iter().map(f).collect()),
+            Self::Unordered(pcs) => Self::Unordered(pcs.into_iter().map(f).collect()),
+        }
+    }
+
+    /// Visit every pitch class in this collection without consuming it.
+    pub fn visit_pitch_classes<F: FnMut(PitchClass)>(&self, mut f: F) {
+        for pc in self.pitch_classes() {
+            f(*pc);
+        }
+    }
+}
+
+impl Render for Collection {
+    fn render(&self, notation: &Notation) -> String {
+        let (open, close) = match self {
+            Self::Unordered(_) => ('{', '}'),
+            Self::Ordered(_) => ('[', ']'),
+        };
+        let body = self
+            .pitch_classes()
+            .iter()
+            .map(|pc| pc.render(notation))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}{}{}", open, body, close)
+    }
+}
+
+impl fmt::Display for Collection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(&Notation::default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use assert2::assert;
+
+    use super::*;
+
+    /// Check that the normal form picks the most left-packed rotation.
+    #[test]
+    fn normal_form() {
+        let triad = Collection::Unordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+        assert!(triad.normal_form() == vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+    }
+
+    /// Check that major and minor triads share a prime form.
+    #[test]
+    fn prime_form() {
+        let major = Collection::Unordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+        let minor = Collection::Unordered(vec![PitchClass::C, PitchClass::Eb, PitchClass::G]);
+        assert!(major.prime_form().to_string() == "(037)");
+        assert!(minor.prime_form().to_string() == "(037)");
+    }
+
+    /// Check the interval vector of a major triad.
+    #[test]
+    fn interval_vector() {
+        let triad = Collection::Unordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+        assert!(triad.interval_vector() == [0, 0, 1, 1, 1, 0]);
+    }
+
+    /// Check that the Forte number of a major triad is looked up correctly.
+    #[test]
+    fn forte_number() {
+        let triad = Collection::Unordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+        assert!(triad.forte_number() == Some("3-11"));
+    }
+
+    /// Check Tn, TnI, and Mm operations on a collection, e.g. the CLI's
+    /// `post-office '{0,4,7}' --transpose 5`.
+    #[test]
+    fn transformations() {
+        let triad = Collection::Unordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+
+        assert!(
+            triad.clone().transpose(5)
+                == Collection::Unordered(vec![PitchClass::F, PitchClass::A, PitchClass::C])
+        );
+        assert!(
+            triad.clone().invert(0)
+                == Collection::Unordered(vec![PitchClass::C, PitchClass::Ab, PitchClass::F])
+        );
+        assert!(
+            triad.multiply(5)
+                == Collection::Unordered(vec![PitchClass::C, PitchClass::Ab, PitchClass::B])
+        );
+    }
+}
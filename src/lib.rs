@@ -1,11 +1,17 @@
 #![feature(min_specialization)]
 #![feature(trait_alias)]
 
+pub mod collection;
 pub mod error;
+mod notation;
+mod operations;
 mod parser;
 pub mod pitch;
 
+pub use self::collection::*;
 pub use self::error::*;
+pub use self::notation::*;
+pub use self::operations::*;
 pub use self::parser::*;
 pub use self::pitch::*;
 
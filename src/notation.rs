@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use crate::{pitch::class::NUMERALS, PitchClass, PostalResult};
+
+/// The accidental used to spell the five black-key pitch classes in
+/// `Style::NoteName`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accidental {
+    Flat,
+    Sharp,
+}
+
+// Note names spelled with flats, indexed by integer pitch class.
+const FLAT_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+// Note names spelled with sharps, indexed by integer pitch class.
+const SHARP_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// The textual style pitch classes are rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Duodecimal numerals (`0`..`9`, `↊`, `↋`).
+    Duodecimal,
+    /// Decimal integers, with `t`/`e` standing in for `10`/`11`.
+    Decimal,
+    /// Note names, spelled with the given accidental.
+    NoteName(Accidental),
+}
+
+/// Configuration for rendering (and parsing) pitch-class notation: which
+/// pitch class counts as `0`, and in what style it and its relatives are
+/// written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Notation {
+    /// The pitch class that text notation refers to as `0`.
+    pub zero: PitchClass,
+    /// The output style.
+    pub style: Style,
+}
+
+impl Default for Notation {
+    fn default() -> Self {
+        Self {
+            zero: PitchClass::C,
+            style: Style::Duodecimal,
+        }
+    }
+}
+
+impl Notation {
+    /// Render a pitch class as text under this notation.
+    pub fn format_pitch_class(&self, pc: PitchClass) -> String {
+        match self.style {
+            Style::NoteName(accidental) => {
+                let names = match accidental {
+                    Accidental::Flat => &FLAT_NAMES,
+                    Accidental::Sharp => &SHARP_NAMES,
+                };
+                names[pc as usize].to_string()
+            }
+            Style::Duodecimal => NUMERALS[self.shifted(pc) as usize].to_string(),
+            Style::Decimal => match self.shifted(pc) {
+                10 => "t".to_string(),
+                11 => "e".to_string(),
+                n => n.to_string(),
+            },
+        }
+    }
+
+    /// Parse a pitch class rendered under this notation back into a
+    /// `PitchClass`.
+    pub fn parse_pitch_class(&self, s: &str) -> PostalResult<PitchClass> {
+        let parsed = PitchClass::from_str(s).map_err(|_| {
+            crate::PostalError::ParsingFailure(s.to_string(), "notated pitch class")
+        })?;
+        Ok(match self.style {
+            Style::NoteName(_) => parsed,
+            Style::Duodecimal | Style::Decimal => {
+                PitchClass::from_int(parsed as isize + self.zero as isize)
+            }
+        })
+    }
+
+    /// `pc`'s integer notation relative to this notation's zero reference.
+    fn shifted(&self, pc: PitchClass) -> isize {
+        (pc as isize - self.zero as isize).rem_euclid(12)
+    }
+}
+
+/// Renders a value as text under a given `Notation`, rather than the fixed
+/// style `Display` uses.
+pub trait Render {
+    fn render(&self, notation: &Notation) -> String;
+}
+
+impl Render for PitchClass {
+    fn render(&self, notation: &Notation) -> String {
+        notation.format_pitch_class(*self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use assert2::assert;
+
+    use super::*;
+    use crate::Expression;
+
+    /// Check that every style round-trips: rendering a pitch class and
+    /// parsing it back under the same notation yields the original pitch
+    /// class.
+    #[test]
+    fn round_trip() {
+        let notations = [
+            Notation {
+                zero: PitchClass::C,
+                style: Style::Duodecimal,
+            },
+            Notation {
+                zero: PitchClass::D,
+                style: Style::Duodecimal,
+            },
+            Notation {
+                zero: PitchClass::C,
+                style: Style::Decimal,
+            },
+            Notation {
+                zero: PitchClass::Gb,
+                style: Style::Decimal,
+            },
+            Notation {
+                zero: PitchClass::C,
+                style: Style::NoteName(Accidental::Flat),
+            },
+            Notation {
+                zero: PitchClass::C,
+                style: Style::NoteName(Accidental::Sharp),
+            },
+        ];
+
+        for notation in notations {
+            for pc in (0..12).map(PitchClass::from_int) {
+                let rendered = notation.format_pitch_class(pc);
+                let parsed = notation.parse_pitch_class(&rendered);
+                assert!(parsed.is_ok(), "notation: {:?}; rendered: {:?}", notation, rendered);
+                assert!(
+                    parsed.unwrap() == pc,
+                    "notation: {:?}; rendered: {:?}",
+                    notation,
+                    rendered
+                );
+            }
+        }
+    }
+
+    /// Check that rendering an expression under a non-default notation and
+    /// parsing it back with `from_str_with_notation` under that same
+    /// notation recovers the original expression.
+    #[test]
+    fn expression_round_trip() {
+        let expression = Expression::from_str("{0,4,7}").unwrap();
+        let notation = Notation {
+            zero: PitchClass::D,
+            style: Style::Decimal,
+        };
+        let rendered = expression.render(&notation);
+        let parsed = Expression::from_str_with_notation(&rendered, &notation).unwrap();
+        assert!(parsed == expression, "rendered: {:?}", rendered);
+    }
+}
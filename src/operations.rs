@@ -0,0 +1,20 @@
+/// Canonical post-tonal transformations — transposition (`Tn`), inversion
+/// (`TnI`), and multiplication (`Mm`) — defined uniformly across pitch
+/// classes, pitches, and collections.
+pub trait Operations: Sized {
+    /// `Tn`: transpose by `n` semitones.
+    fn transpose(self, n: isize) -> Self;
+
+    /// Invert about `axis`, mapping each pitch class `x` to
+    /// `(axis - x) mod 12`.
+    fn invert_about(self, axis: isize) -> Self;
+
+    /// `TnI`: invert about 0, then transpose by `n`, i.e. map each pitch
+    /// class `x` to `(n - x) mod 12`.
+    fn invert(self, n: isize) -> Self {
+        self.invert_about(n)
+    }
+
+    /// `Mm`: multiply by `m` mod 12.
+    fn multiply(self, m: isize) -> Self;
+}
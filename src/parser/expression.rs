@@ -1,47 +1,164 @@
-use std::{fmt, str::FromStr};
+use std::fmt;
 
 use anyhow::Result;
 use pest::{iterators::Pair, Parser};
 
 use crate::{
+    notation::Render,
+    operations::Operations,
     parser::{ExpressionParser, Rule},
-    Pitch, PitchClass,
+    Collection, Notation, Pitch, PitchClass,
 };
 
 /// A complete expression.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expression {
     Pitch(Pitch),
     PitchClass(PitchClass),
-    Collection,
+    Collection(Collection),
 }
 
 impl Expression {
     pub fn from_str(e: &str) -> Result<Self> {
+        Self::from_str_with_notation(e, &Notation::default())
+    }
+
+    /// Parse a string as an expression, reading its pitch classes under
+    /// `notation`'s zero reference rather than assuming `0` is `C`. This is
+    /// the inverse of `Render::render` under the same `notation`: rendering
+    /// an expression and parsing it back with `from_str_with_notation` under
+    /// the same notation yields the original expression.
+    pub fn from_str_with_notation(e: &str, notation: &Notation) -> Result<Self> {
         log::debug!("Parsing string '{}' as an expression.", e);
         let mut pairs = ExpressionParser::parse(Rule::expression, e)?;
 
-        Ok(Self::from_pair(pairs.next().unwrap()))
+        Ok(Self::from_pair(pairs.next().unwrap(), notation))
     }
 
-    fn from_pair(p: Pair<Rule>) -> Self {
+    fn from_pair(p: Pair<Rule>, notation: &Notation) -> Self {
         log::trace!("Parsing pair `{}`.", p);
         match p.as_rule() {
-            Rule::expression => Self::from_pair(p.into_inner().next().unwrap()),
+            Rule::expression => Self::from_pair(p.into_inner().next().unwrap(), notation),
+            Rule::pitch => Expression::Pitch(Pitch::from_pair(p).unwrap()),
             Rule::pitch_class_permissive | Rule::pitch_class_strict => Expression::PitchClass(
-                PitchClass::from_str(p.into_inner().next().unwrap().as_str()).unwrap(),
+                notation
+                    .parse_pitch_class(p.into_inner().next().unwrap().as_str())
+                    .unwrap(),
             ),
+            Rule::collection_unordered | Rule::collection_ordered => {
+                let ordered = p.as_rule() == Rule::collection_ordered;
+                let pcs: Vec<PitchClass> = p
+                    .into_inner()
+                    .map(|pc| notation.parse_pitch_class(pc.as_str()).unwrap())
+                    .collect();
+                Expression::Collection(if ordered {
+                    Collection::Ordered(pcs)
+                } else {
+                    Collection::Unordered(pcs)
+                })
+            }
             _ => todo!(),
         }
     }
 }
 
-impl fmt::Display for Expression {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Operations for Expression {
+    fn transpose(self, n: isize) -> Self {
+        match self {
+            Self::Pitch(p) => Self::Pitch(p.transpose(n)),
+            Self::PitchClass(pc) => Self::PitchClass(pc.transpose(n)),
+            Self::Collection(c) => Self::Collection(c.transpose(n)),
+        }
+    }
+
+    fn invert_about(self, axis: isize) -> Self {
+        match self {
+            Self::Pitch(p) => Self::Pitch(p.invert_about(axis)),
+            Self::PitchClass(pc) => Self::PitchClass(pc.invert_about(axis)),
+            Self::Collection(c) => Self::Collection(c.invert_about(axis)),
+        }
+    }
+
+    fn multiply(self, m: isize) -> Self {
+        match self {
+            Self::Pitch(p) => Self::Pitch(p.multiply(m)),
+            Self::PitchClass(pc) => Self::PitchClass(pc.multiply(m)),
+            Self::Collection(c) => Self::Collection(c.multiply(m)),
+        }
+    }
+}
+
+impl Render for Expression {
+    fn render(&self, notation: &Notation) -> String {
         match self {
-            Self::Pitch(p) => write!(f, "{:?}", p),
-            Self::PitchClass(pc) => pc.fmt(f),
-            Self::Collection => write!(f, "{{unimplemented}}"),
+            Self::Pitch(p) => p.render(notation),
+            Self::PitchClass(pc) => pc.render(notation),
+            Self::Collection(c) => c.render(notation),
         }
     }
 }
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(&Notation::default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use assert2::assert;
+
+    use super::*;
+
+    /// Check that a bare pitch parses through the expression's one public
+    /// entry point, not just via `Pitch::from_str` directly.
+    #[test]
+    fn pitch_from_str() {
+        assert!(Expression::from_str("C4").unwrap() == Expression::Pitch(Pitch::new(PitchClass::C, 4)));
+        assert!(
+            Expression::from_str("G#5").unwrap() == Expression::Pitch(Pitch::new(PitchClass::Ab, 5))
+        );
+    }
+
+    /// Check Tn/TnI/Mm dispatch for `Expression::Collection`, the shape the
+    /// CLI actually exercises (e.g. `post-office '{0,4,7}' --transpose 5`).
+    #[test]
+    fn collection_operations() {
+        let triad =
+            Expression::Collection(Collection::Unordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]));
+
+        assert!(
+            triad.clone().transpose(5)
+                == Expression::Collection(Collection::Unordered(vec![
+                    PitchClass::F,
+                    PitchClass::A,
+                    PitchClass::C
+                ]))
+        );
+        assert!(
+            triad.clone().invert(0)
+                == Expression::Collection(Collection::Unordered(vec![
+                    PitchClass::C,
+                    PitchClass::Ab,
+                    PitchClass::F
+                ]))
+        );
+        assert!(
+            triad.multiply(5)
+                == Expression::Collection(Collection::Unordered(vec![
+                    PitchClass::C,
+                    PitchClass::Ab,
+                    PitchClass::B
+                ]))
+        );
+    }
+
+    /// Check that `Operations` on an octave-aware `Expression::Pitch` still
+    /// rolls over octaves correctly through the dispatch in this module.
+    #[test]
+    fn pitch_operations() {
+        let b4 = Expression::Pitch(Pitch::new(PitchClass::B, 4));
+        assert!(b4.transpose(1) == Expression::Pitch(Pitch::new(PitchClass::C, 5)));
+    }
+}
@@ -1,5 +1,6 @@
 mod component;
 mod expression;
+mod visitor;
 
 use pest_derive::Parser;
 
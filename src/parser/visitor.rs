@@ -0,0 +1,89 @@
+use crate::{Expression, PitchClass};
+
+impl Expression {
+    /// Map `f` over every pitch class contained in this expression,
+    /// regardless of whether it is a bare pitch class, a pitch, or a
+    /// collection, returning the transformed expression.
+    ///
+    /// This operates purely in pitch-class space: for a `Pitch`, only its
+    /// pitch class is passed to `f` and its octave is left unchanged. It is
+    /// **not** octave-safe, so it must not be used to transpose, invert, or
+    /// multiply a `Pitch` — use `Operations::transpose`/`invert_about`/
+    /// `multiply` for that, which compute the result from the pitch's
+    /// absolute semitone position and shift octaves correctly. Reach for
+    /// this visitor only when `f` is meant to act on the pitch class alone.
+    pub fn map_pitch_classes<F: FnMut(PitchClass) -> PitchClass>(self, mut f: F) -> Self {
+        match self {
+            Self::PitchClass(pc) => Self::PitchClass(f(pc)),
+            Self::Pitch(p) => Self::Pitch(p.map_pitch_class(f)),
+            Self::Collection(c) => Self::Collection(c.map_pitch_classes(f)),
+        }
+    }
+
+    /// Visit every pitch class contained in this expression without
+    /// consuming it.
+    pub fn visit_pitch_classes<F: FnMut(PitchClass)>(&self, mut f: F) {
+        match self {
+            Self::PitchClass(pc) => f(*pc),
+            Self::Pitch(p) => f(p.class()),
+            Self::Collection(c) => c.visit_pitch_classes(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use assert2::assert;
+
+    use super::*;
+    use crate::{operations::Operations, Collection, Pitch};
+
+    /// Check that `map_pitch_classes` transforms every pitch class in an
+    /// expression, regardless of shape.
+    #[test]
+    fn map_pitch_classes() {
+        let collection =
+            Expression::Collection(Collection::Unordered(vec![PitchClass::C, PitchClass::E]));
+        let mapped = collection.map_pitch_classes(|pc| pc.transpose(1));
+        assert!(matches!(
+            mapped,
+            Expression::Collection(Collection::Unordered(pcs)) if pcs == vec![PitchClass::Db, PitchClass::F]
+        ));
+
+        let pitch = Expression::Pitch(Pitch::new(PitchClass::C, 4));
+        let mapped = pitch.map_pitch_classes(|pc| pc.transpose(1));
+        assert!(matches!(
+            mapped,
+            Expression::Pitch(p) if p == Pitch::new(PitchClass::Db, 4)
+        ));
+    }
+
+    /// Document that `map_pitch_classes` is not octave-safe: mapping a
+    /// `Pitch` across the `C` boundary leaves its octave untouched, unlike
+    /// `Operations::transpose`, which correctly rolls over into the next
+    /// octave. Callers that need correct transposition must use
+    /// `Operations::transpose`, not this visitor.
+    #[test]
+    fn map_pitch_classes_does_not_cross_octaves() {
+        let pitch = Expression::Pitch(Pitch::new(PitchClass::B, 4));
+        let mapped = pitch.map_pitch_classes(|pc| pc.transpose(1));
+        assert!(matches!(
+            mapped,
+            Expression::Pitch(p) if p == Pitch::new(PitchClass::C, 4)
+        ));
+
+        let correctly_transposed = Pitch::new(PitchClass::B, 4).transpose(1);
+        assert!(correctly_transposed == Pitch::new(PitchClass::C, 5));
+    }
+
+    /// Check that `visit_pitch_classes` visits every pitch class without
+    /// consuming the expression.
+    #[test]
+    fn visit_pitch_classes() {
+        let collection =
+            Expression::Collection(Collection::Ordered(vec![PitchClass::C, PitchClass::E, PitchClass::G]));
+        let mut visited = Vec::new();
+        collection.visit_pitch_classes(|pc| visited.push(pc));
+        assert!(visited == vec![PitchClass::C, PitchClass::E, PitchClass::G]);
+    }
+}
@@ -12,6 +12,7 @@ pub trait IntegerPitchClass = PrimInt + AsPrimitive<isize>;
 /// A `PitchClass` corresponds to all notes with the same name, regardless of
 /// octave.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PitchClass {
     C = 0,
     Db = 1,
@@ -60,7 +61,7 @@ const ACCIDENTALS: phf::Map<&'static str, i8> = phf_map! {
 };
 
 // Mapping from pitch classes to their numerals.
-const NUMERALS: [&'static str; 12] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "â†Š", "â†‹"];
+pub(crate) const NUMERALS: [&'static str; 12] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "â†Š", "â†‹"];
 // Mapping of transdecimal numerals used for parsing.
 const TRANSDECIMAL_NUMERALS: phf::OrderedMap<&'static str, PitchClass> = phf_ordered_map! {
     "t" => PitchClass::Bb,
@@ -100,14 +101,14 @@ macro_rules! pc {
 
 impl fmt::Display for PitchClass {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", NUMERALS[*self as usize])
+        write!(f, "{}", crate::notation::Notation::default().format_pitch_class(*self))
     }
 }
 
-mod conversions {
+pub(crate) mod conversions {
     use super::*;
 
-    mod string {
+    pub(crate) mod string {
         use std::str::FromStr;
 
         use lazy_static::lazy_static;
@@ -123,7 +124,7 @@ mod conversions {
 
         /// Parse a `pest` pair into a pitch class. The pair must be a `note_strict`
         /// or a `note_permissive` rule.
-        fn parse_note(p: Pair<Rule>) -> PostalResult<PitchClass> {
+        pub(crate) fn parse_note(p: Pair<Rule>) -> PostalResult<PitchClass> {
             let strict = p.as_rule() == Rule::note_strict;
             assert!(strict || p.as_rule() == Rule::note_permissive);
 
@@ -344,6 +345,8 @@ mod conversions {
 mod operations {
     use std::ops;
 
+    use crate::operations::Operations;
+
     use super::*;
 
     impl<I: IntegerPitchClass> ops::Add<I> for PitchClass {
@@ -361,6 +364,20 @@ mod operations {
             Self::from(self as isize - rhs.as_())
         }
     }
+
+    impl Operations for PitchClass {
+        fn transpose(self, n: isize) -> Self {
+            self + n
+        }
+
+        fn invert_about(self, axis: isize) -> Self {
+            Self::from_int(axis - self as isize)
+        }
+
+        fn multiply(self, m: isize) -> Self {
+            Self::from_int(self as isize * m)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -418,6 +435,17 @@ mod test {
         }
     }
 
+    /// Check Tn, TnI, and Mm operations on pitch classes.
+    #[test]
+    fn transformations() {
+        use crate::operations::Operations;
+
+        assert!(PitchClass::C.transpose(5) == PitchClass::F);
+        assert!(PitchClass::E.invert_about(0) == PitchClass::Ab);
+        assert!(PitchClass::E.invert(7) == PitchClass::Eb);
+        assert!(PitchClass::D.multiply(5) == PitchClass::Bb);
+    }
+
     /// Check the display of pitch classes.
     #[test]
     fn display() {
@@ -3,16 +3,20 @@ pub mod class;
 use std::{cmp::Ordering, str::FromStr};
 
 use anyhow::{Error, Result};
-use pest::Parser;
+use pest::{iterators::Pair, Parser};
 
 pub use self::class::*;
-use crate::{ExpressionParser, PostalError, Rule};
+use crate::{
+    notation::Render, operations::Operations, Accidental, ExpressionParser, Notation, PostalError, Rule,
+    Style,
+};
 
 /// An octave specifies how high or low a pitch is.
 pub type Octave = i8;
 
 /// A pitch consists of a pair of pitch class and octave.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pitch {
     /// This note's pitch class.
     class: PitchClass,
@@ -27,6 +31,121 @@ impl Pitch {
             octave: oct,
         }
     }
+
+    /// This pitch's pitch class.
+    pub fn class(&self) -> PitchClass {
+        self.class
+    }
+
+    /// This pitch's octave.
+    pub fn octave(&self) -> Octave {
+        self.octave
+    }
+
+    /// This pitch's absolute position in semitones, taking octave 0's `C`
+    /// as `0`.
+    fn absolute(&self) -> isize {
+        self.class as isize + self.octave as isize * 12
+    }
+
+    /// The ordered pitch interval from this pitch to `other`, in semitones,
+    /// signed and spanning octaves.
+    pub fn pitch_interval(&self, other: &Self) -> isize {
+        other.absolute() - self.absolute()
+    }
+
+    /// The directed pitch-class interval from this pitch to `other`: the
+    /// pitch interval reduced mod 12.
+    pub fn directed_interval_class(&self, other: &Self) -> isize {
+        self.pitch_interval(other).rem_euclid(12)
+    }
+
+    /// The undirected interval class between this pitch and `other`: the
+    /// smaller of its ascending and descending pitch-class intervals.
+    pub fn interval_class(&self, other: &Self) -> isize {
+        let d = self.directed_interval_class(other);
+        d.min(12 - d)
+    }
+
+    /// Map `f` over this pitch's pitch class, keeping its octave unchanged.
+    ///
+    /// This does not cross octave boundaries: it is **not** equivalent to
+    /// `Operations::transpose`, which computes the shift from this pitch's
+    /// absolute semitone position and rolls over into neighboring octaves.
+    pub fn map_pitch_class<F: FnOnce(PitchClass) -> PitchClass>(self, f: F) -> Self {
+        Self {
+            class: f(self.class),
+            octave: self.octave,
+        }
+    }
+
+    /// Build a pitch from a `Rule::pitch` pair, as parsed by
+    /// [`ExpressionParser`]. Shared by `FromStr` and `Expression::from_pair`
+    /// so there is a single place that understands the grammar.
+    pub(crate) fn from_pair(p: Pair<Rule>) -> Result<Self> {
+        assert_eq!(p.as_rule(), Rule::pitch);
+
+        let mut p = p.into_inner();
+        let note = p.next().unwrap();
+        let octave = p.next().unwrap();
+
+        assert_eq!(note.as_rule(), Rule::note_permissive);
+        assert_eq!(octave.as_rule(), Rule::octave);
+
+        let class = class::conversions::string::parse_note(note)?;
+        let octave: Octave = octave.as_str().parse()?;
+
+        Ok(Self { class, octave })
+    }
+}
+
+mod operations {
+    use super::*;
+
+    impl Operations for Pitch {
+        fn transpose(self, n: isize) -> Self {
+            let absolute = self.absolute() + n;
+            Self {
+                class: PitchClass::from_int(absolute),
+                octave: absolute.div_euclid(12) as Octave,
+            }
+        }
+
+        fn invert_about(self, axis: isize) -> Self {
+            let absolute = axis - self.absolute();
+            Self {
+                class: PitchClass::from_int(absolute),
+                octave: absolute.div_euclid(12) as Octave,
+            }
+        }
+
+        fn multiply(self, m: isize) -> Self {
+            let absolute = self.absolute() * m;
+            Self {
+                class: PitchClass::from_int(absolute),
+                octave: absolute.div_euclid(12) as Octave,
+            }
+        }
+    }
+}
+
+mod notation {
+    use super::*;
+
+    impl Render for Pitch {
+        /// Pitches only parse with a note name, so numeral styles render the
+        /// pitch class as a flat note name to keep the output parseable.
+        fn render(&self, notation: &Notation) -> String {
+            let notation = match notation.style {
+                Style::NoteName(_) => *notation,
+                Style::Duodecimal | Style::Decimal => Notation {
+                    style: Style::NoteName(Accidental::Flat),
+                    ..*notation
+                },
+            };
+            format!("{}{}", self.class.render(&notation), self.octave)
+        }
+    }
 }
 
 impl PartialOrd<Self> for Pitch {
@@ -51,16 +170,7 @@ impl FromStr for Pitch {
         let p = ExpressionParser::parse(Rule::pitch, s)?.next();
 
         if let Some(p) = p {
-            assert_eq!(p.as_rule(), Rule::pitch);
-
-            let mut p = p.into_inner();
-            let note = p.next().unwrap();
-            let octave = p.next().unwrap();
-
-            assert_eq!(note.as_rule(), Rule::note_permissive);
-            assert_eq!(octave.as_rule(), Rule::octave);
-
-            todo!()
+            Self::from_pair(p)
         } else {
             Err(PostalError::ParsingFailure(s.to_string(), "pitch").into())
         }
@@ -80,4 +190,32 @@ mod test {
         assert!(Pitch::new(PitchClass::G, 2) < Pitch::new(PitchClass::C, 4));
         assert!(Pitch::new(PitchClass::A, 6) > Pitch::new(PitchClass::F, 4));
     }
+
+    /// Check that transposition shifts octaves when crossing the C boundary.
+    #[test]
+    fn transposition_crosses_octave() {
+        assert!(Pitch::new(PitchClass::B, 4).transpose(1) == Pitch::new(PitchClass::C, 5));
+        assert!(Pitch::new(PitchClass::C, 4).transpose(-1) == Pitch::new(PitchClass::B, 3));
+    }
+
+    /// Check that pitches parse from strings.
+    #[test]
+    fn from_str() {
+        assert!(Pitch::from_str("C4").unwrap() == Pitch::new(PitchClass::C, 4));
+        assert!(Pitch::from_str("G#5").unwrap() == Pitch::new(PitchClass::Ab, 5));
+        assert!(Pitch::from_str("Bb-1").unwrap() == Pitch::new(PitchClass::Bb, -1));
+    }
+
+    /// Check pitch-interval and interval-class arithmetic between pitches.
+    #[test]
+    fn intervals() {
+        let c4 = Pitch::new(PitchClass::C, 4);
+        let g5 = Pitch::new(PitchClass::G, 5);
+
+        assert!(c4.pitch_interval(&g5) == 19);
+        assert!(g5.pitch_interval(&c4) == -19);
+        assert!(c4.directed_interval_class(&g5) == 7);
+        assert!(g5.directed_interval_class(&c4) == 5);
+        assert!(c4.interval_class(&g5) == 5);
+    }
 }